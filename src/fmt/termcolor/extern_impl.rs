@@ -1,5 +1,6 @@
 use std::error::Error;
 use std::borrow::Cow;
+use std::env;
 use std::fmt;
 use std::io::{self, Write};
 use std::str::FromStr;
@@ -42,15 +43,21 @@ impl Formatter {
     ///
     /// [`Style`]: struct.Style.html
     pub fn style(&self) -> Style {
+        let depth = self.buf.borrow().depth;
         Style {
             buf: self.buf.clone(),
             spec: ColorSpec::new(),
+            depth,
         }
     }
 
     /// Get the default [`Style`] for the given level.
-    /// 
+    ///
     /// The style can be used to print other values besides the level.
+    ///
+    /// If the `RUST_LOG_COLORS` environment variable customizes the `level`
+    /// field, the matching attributes from that spec are layered on top of
+    /// the built-in level colors.
     pub fn default_level_style(&self, level: Level) -> Style {
         let mut level_style = self.style();
         match level {
@@ -60,6 +67,9 @@ impl Formatter {
             Level::Warn => level_style.set_color(Color::Yellow),
             Level::Error => level_style.set_color(Color::Red).set_bold(true),
         };
+
+        self.buf.borrow().styles.level().apply(&mut level_style);
+
         level_style
     }
 
@@ -71,46 +81,69 @@ impl Formatter {
     }
 }
 
-pub(in ::fmt) struct BufferWriter(termcolor::BufferWriter);
-pub(in ::fmt) struct Buffer(termcolor::Buffer);
+pub(in ::fmt) struct BufferWriter {
+    inner: termcolor::BufferWriter,
+    depth: ColorDepth,
+    styles: Rc<StyleOverrides>,
+}
+
+pub(in ::fmt) struct Buffer {
+    inner: termcolor::Buffer,
+    depth: ColorDepth,
+    styles: Rc<StyleOverrides>,
+}
 
 impl BufferWriter {
     pub(in ::fmt) fn stderr(write_style: WriteStyle) -> Self {
-        BufferWriter(termcolor::BufferWriter::stderr(write_style.into_color_choice()))
+        let depth = ColorDepth::for_write_style(&write_style);
+        BufferWriter {
+            inner: termcolor::BufferWriter::stderr(write_style.into_color_choice()),
+            depth,
+            styles: Rc::new(StyleOverrides::detect()),
+        }
     }
 
     pub(in ::fmt) fn stdout(write_style: WriteStyle) -> Self {
-        BufferWriter(termcolor::BufferWriter::stdout(write_style.into_color_choice()))
+        let depth = ColorDepth::for_write_style(&write_style);
+        BufferWriter {
+            inner: termcolor::BufferWriter::stdout(write_style.into_color_choice()),
+            depth,
+            styles: Rc::new(StyleOverrides::detect()),
+        }
     }
 
     pub(in ::fmt) fn buffer(&self) -> Buffer {
-        Buffer(self.0.buffer())
+        Buffer {
+            inner: self.inner.buffer(),
+            depth: self.depth,
+            styles: self.styles.clone(),
+        }
     }
 
     pub(in ::fmt) fn print(&self, buf: &Buffer) -> io::Result<()> {
-        self.0.print(&buf.0)
+        self.inner.print(&buf.inner)
     }
 }
 
 impl Buffer {
     pub(in ::fmt) fn clear(&mut self) {
-        self.0.clear()
+        self.inner.clear()
     }
 
     pub(in ::fmt) fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.0.write(buf)
+        self.inner.write(buf)
     }
 
     pub(in ::fmt) fn flush(&mut self) -> io::Result<()> {
-        self.0.flush()
+        self.inner.flush()
     }
 
     fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
-        self.0.set_color(spec)
+        self.inner.set_color(spec)
     }
 
     fn reset(&mut self) -> io::Result<()> {
-        self.0.reset()
+        self.inner.reset()
     }
 }
 
@@ -124,6 +157,267 @@ impl WriteStyle {
     }
 }
 
+/// The color depth supported by the terminal a [`Style`] is written to.
+///
+/// `Color::Rgb` and `Color::Ansi256` only render correctly on terminals that
+/// advertise truecolor or 256-color support; everywhere else they're
+/// silently dropped. A `Style` quantizes `Color::Rgb` down to the depth
+/// reported here instead, so it degrades to its closest approximation
+/// rather than disappearing. `Color::Ansi256` is not quantized: it is
+/// written as given at every depth.
+///
+/// [`Style`]: struct.Style.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ColorDepth {
+    /// The terminal supports 24-bit RGB and the 256-color palette natively.
+    TrueColor,
+    /// The terminal supports the 256-color palette, but not arbitrary RGB.
+    Ansi256,
+    /// The terminal only supports the 16 standard ANSI colors.
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Work out the color depth to quantize to for the given [`WriteStyle`].
+    ///
+    /// Degradation is opt-in: it only kicks in for `WriteStyle::Auto`, where
+    /// sniffing the environment is already how color is decided at all. A
+    /// caller that forces `WriteStyle::Always` (or turns color off entirely
+    /// with `WriteStyle::Never`) is assumed to know their terminal supports
+    /// what it's asked for, so `Color::Rgb`/`Color::Ansi256` are passed
+    /// through untouched.
+    ///
+    /// [`WriteStyle`]: ../../enum.WriteStyle.html
+    fn for_write_style(write_style: &WriteStyle) -> Self {
+        match *write_style {
+            WriteStyle::Auto => ColorDepth::detect(),
+            WriteStyle::Always | WriteStyle::Never => ColorDepth::TrueColor,
+        }
+    }
+
+    /// Detect the color depth of the current terminal from the environment.
+    ///
+    /// A `COLORTERM` of `truecolor` or `24bit` is taken to mean full RGB
+    /// support, a `TERM` containing `256color` means the 256-color palette
+    /// is supported, and anything else is assumed to only support the 16
+    /// standard ANSI colors.
+    fn detect() -> Self {
+        if let Ok(colorterm) = env::var("COLORTERM") {
+            if colorterm == "truecolor" || colorterm == "24bit" {
+                return ColorDepth::TrueColor;
+            }
+        }
+
+        if let Ok(term) = env::var("TERM") {
+            if term.contains("256color") {
+                return ColorDepth::Ansi256;
+            }
+        }
+
+        ColorDepth::Ansi16
+    }
+}
+
+/// The 16 standard ANSI colors, in the order their codes are assigned, used
+/// to find the closest match when degrading to `ColorDepth::Ansi16`.
+const ANSI16_TABLE: [(Color, u8, u8, u8); 16] = [
+    (Color::Black, 0, 0, 0),
+    (Color::Red, 128, 0, 0),
+    (Color::Green, 0, 128, 0),
+    (Color::Yellow, 128, 128, 0),
+    (Color::Blue, 0, 0, 128),
+    (Color::Magenta, 128, 0, 128),
+    (Color::Cyan, 0, 128, 128),
+    (Color::White, 192, 192, 192),
+    (Color::Black, 128, 128, 128),
+    (Color::Red, 255, 0, 0),
+    (Color::Green, 0, 255, 0),
+    (Color::Yellow, 255, 255, 0),
+    (Color::Blue, 0, 0, 255),
+    (Color::Magenta, 255, 0, 255),
+    (Color::Cyan, 0, 255, 255),
+    (Color::White, 255, 255, 255),
+];
+
+/// Map an 8-bit channel onto the 0-5 steps of the 256-color cube.
+fn channel_to_cube_step(value: u8) -> u16 {
+    (value as f32 / 255.0 * 5.0).round() as u16
+}
+
+/// Downsample an RGB color to the nearest color in the 256-color palette.
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    // Colors close to gray look better on the dedicated grayscale ramp
+    // (indices 232-255) than on the color cube.
+    let is_gray = (r as i16 - g as i16).abs() <= 2
+        && (g as i16 - b as i16).abs() <= 2
+        && (r as i16 - b as i16).abs() <= 2;
+
+    if is_gray {
+        let gray = (r as u16 + g as u16 + b as u16) / 3;
+        return 232 + (gray as f32 / 255.0 * 23.0).round() as u8;
+    }
+
+    let r = channel_to_cube_step(r);
+    let g = channel_to_cube_step(g);
+    let b = channel_to_cube_step(b);
+    (16 + 36 * r + 6 * g + b) as u8
+}
+
+/// Downsample an RGB color to the closest of the 16 standard ANSI colors,
+/// returning whether the match is in the bright half of the table and so
+/// needs the `intense` attribute set to reach it.
+fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> (Color, bool) {
+    let (index, (color, _, _, _)) = ANSI16_TABLE
+        .iter()
+        .enumerate()
+        .min_by_key(|&(_, &(_, tr, tg, tb))| {
+            let dr = r as i32 - tr as i32;
+            let dg = g as i32 - tg as i32;
+            let db = b as i32 - tb as i32;
+            dr * dr + dg * dg + db * db
+        })
+        .expect("ANSI16_TABLE is non-empty");
+
+    (color.clone(), index >= 8)
+}
+
+/// The `fg`/`bg`/`style` attributes to layer on top of the `level` field's
+/// default style, as parsed out of one or more `RUST_LOG_COLORS` directives.
+#[derive(Clone, Debug, Default)]
+struct StyleOverride {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    underline: bool,
+    italic: bool,
+    dimmed: bool,
+    intense: bool,
+    strikethrough: bool,
+}
+
+impl StyleOverride {
+    fn apply(&self, style: &mut Style) {
+        if let Some(ref fg) = self.fg {
+            style.set_color(fg.clone());
+        }
+        if let Some(ref bg) = self.bg {
+            style.set_bg(bg.clone());
+        }
+        if self.bold {
+            style.set_bold(true);
+        }
+        if self.underline {
+            style.set_underline(true);
+        }
+        if self.italic {
+            style.set_italic(true);
+        }
+        if self.dimmed {
+            style.set_dimmed(true);
+        }
+        if self.intense {
+            style.set_intense(true);
+        }
+        if self.strikethrough {
+            style.set_strikethrough(true);
+        }
+    }
+
+    fn apply_directive(&mut self, attr: &str, value: &str) {
+        match attr {
+            "fg" => {
+                if let Ok(color) = Color::from_str(value) {
+                    self.fg = Some(color);
+                }
+            }
+            "bg" => {
+                if let Ok(color) = Color::from_str(value) {
+                    self.bg = Some(color);
+                }
+            }
+            "style" => match value {
+                "bold" => self.bold = true,
+                "underline" => self.underline = true,
+                "italic" => self.italic = true,
+                "dimmed" => self.dimmed = true,
+                "intense" => self.intense = true,
+                "strikethrough" => self.strikethrough = true,
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+/// The style overrides parsed from `RUST_LOG_COLORS`.
+///
+/// The spec grammar is `field:attr:value`, in the same shape ripgrep's
+/// `--colors` uses, but only a `level` field is recognized right now: it's
+/// the only part of the default output `default_level_style` can actually
+/// recolor. A directive for `target`, `args` or `timestamp` is valid
+/// grammar the crate can't act on yet, so it's reported with a warning on
+/// stderr and otherwise ignored, rather than silently dropped.
+#[derive(Clone, Debug, Default)]
+struct StyleOverrides {
+    level: StyleOverride,
+}
+
+impl StyleOverrides {
+    /// Read and parse `RUST_LOG_COLORS` from the environment.
+    ///
+    /// An unset or unparseable directive is ignored rather than rejected,
+    /// so a typo in one field doesn't take down styling for the rest.
+    fn detect() -> Self {
+        match env::var("RUST_LOG_COLORS") {
+            Ok(spec) => StyleOverrides::parse(&spec),
+            Err(_) => StyleOverrides::default(),
+        }
+    }
+
+    /// Parse a `field:attr:value` directive list, like ripgrep's `--colors`:
+    ///
+    /// ```text
+    /// level:fg:red,level:style:bold
+    /// ```
+    fn parse(spec: &str) -> Self {
+        let mut overrides = StyleOverrides::default();
+
+        for directive in spec.split(',') {
+            let directive = directive.trim();
+            if directive.is_empty() {
+                continue;
+            }
+
+            let mut parts = directive.splitn(3, ':');
+            let field = parts.next();
+            let attr = parts.next();
+            let value = parts.next();
+
+            match (field, attr, value) {
+                (Some("level"), Some(attr), Some(value)) => {
+                    overrides.level.apply_directive(attr, value);
+                }
+                (Some("target"), Some(_), Some(_))
+                | (Some("args"), Some(_), Some(_))
+                | (Some("timestamp"), Some(_), Some(_)) => {
+                    eprintln!(
+                        "env_logger: ignoring RUST_LOG_COLORS directive {:?}: the `{}` field isn't supported yet, only `level` is",
+                        directive,
+                        field.expect("matched a Some(_) field above"),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        overrides
+    }
+
+    fn level(&self) -> &StyleOverride {
+        &self.level
+    }
+}
+
 /// A set of styles to apply to the terminal output.
 ///
 /// Call [`Formatter::style`] to get a `Style` and use the builder methods to
@@ -180,6 +474,7 @@ impl WriteStyle {
 pub struct Style {
     buf: Rc<RefCell<Buffer>>,
     spec: ColorSpec,
+    depth: ColorDepth,
 }
 
 /// A value that can be printed using the given styles.
@@ -214,7 +509,11 @@ impl Style {
     /// });
     /// ```
     pub fn set_color(&mut self, color: Color) -> &mut Style {
-        self.spec.set_fg(color.into_termcolor());
+        let (color, intense) = color.quantize(self.depth);
+        self.spec.set_fg(color);
+        if let Some(intense) = intense {
+            self.spec.set_intense(intense);
+        }
         self
     }
 
@@ -293,7 +592,119 @@ impl Style {
     /// });
     /// ```
     pub fn set_bg(&mut self, color: Color) -> &mut Style {
-        self.spec.set_bg(color.into_termcolor());
+        let (color, intense) = color.quantize(self.depth);
+        self.spec.set_bg(color);
+        if let Some(intense) = intense {
+            self.spec.set_intense(intense);
+        }
+        self
+    }
+
+    /// Set whether the text is underlined.
+    ///
+    /// If `yes` is true then an underline will be printed under the text.
+    /// If `yes` is false then the text will not be underlined.
+    ///
+    /// # Examples
+    ///
+    /// Create a style with underlined text:
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// let mut builder = env_logger::Builder::new();
+    ///
+    /// builder.format(|buf, record| {
+    ///     let mut style = buf.style();
+    ///
+    ///     style.set_underline(true);
+    ///
+    ///     writeln!(buf, "{}", style.value(record.args()))
+    /// });
+    /// ```
+    pub fn set_underline(&mut self, yes: bool) -> &mut Style {
+        self.spec.set_underline(yes);
+        self
+    }
+
+    /// Set whether the text is italicized.
+    ///
+    /// If `yes` is true then the text will be written in italics.
+    /// If `yes` is false then the text will be written upright.
+    ///
+    /// # Examples
+    ///
+    /// Create a style with italic text:
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// let mut builder = env_logger::Builder::new();
+    ///
+    /// builder.format(|buf, record| {
+    ///     let mut style = buf.style();
+    ///
+    ///     style.set_italic(true);
+    ///
+    ///     writeln!(buf, "{}", style.value(record.args()))
+    /// });
+    /// ```
+    pub fn set_italic(&mut self, yes: bool) -> &mut Style {
+        self.spec.set_italic(yes);
+        self
+    }
+
+    /// Set whether the text is dimmed.
+    ///
+    /// If `yes` is true then text will be written in a dimmer color.
+    /// If `yes` is false then text will be written in the default color.
+    ///
+    /// # Examples
+    ///
+    /// Create a style with dimmed text:
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// let mut builder = env_logger::Builder::new();
+    ///
+    /// builder.format(|buf, record| {
+    ///     let mut style = buf.style();
+    ///
+    ///     style.set_dimmed(true);
+    ///
+    ///     writeln!(buf, "{}", style.value(record.args()))
+    /// });
+    /// ```
+    pub fn set_dimmed(&mut self, yes: bool) -> &mut Style {
+        self.spec.set_dimmed(yes);
+        self
+    }
+
+    /// Set whether the text is struck through.
+    ///
+    /// If `yes` is true then a line will be printed through the text.
+    /// If `yes` is false then the text will not be struck through.
+    ///
+    /// # Examples
+    ///
+    /// Create a style with struck through text:
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// let mut builder = env_logger::Builder::new();
+    ///
+    /// builder.format(|buf, record| {
+    ///     let mut style = buf.style();
+    ///
+    ///     style.set_strikethrough(true);
+    ///
+    ///     writeln!(buf, "{}", style.value(record.args()))
+    /// });
+    /// ```
+    pub fn set_strikethrough(&mut self, yes: bool) -> &mut Style {
+        self.spec.set_strikethrough(yes);
         self
     }
 
@@ -401,6 +812,8 @@ impl_styled_value_fmt!(
 /// 2. A single 8-bit integer, in either decimal or hexadecimal format.
 /// 3. A triple of 8-bit integers separated by a comma, where each integer is
 ///    in decimal or hexadecimal format.
+/// 4. A CSS-style hex literal like `#33aaff`, or its 3-digit shorthand
+///    `#3af`, producing an RGB color.
 ///
 /// Hexadecimal numbers are written with a `0x` prefix.
 #[allow(missing_docs)]
@@ -492,6 +905,22 @@ impl Color {
         }
     }
 
+    /// Downsample this color to the given depth before converting it,
+    /// returning whether the `intense` attribute needs to be forced to
+    /// reach the downsampled color.
+    fn quantize(self, depth: ColorDepth) -> (Option<termcolor::Color>, Option<bool>) {
+        match (self, depth) {
+            (Color::Rgb(r, g, b), ColorDepth::Ansi256) => {
+                (Some(termcolor::Color::Ansi256(rgb_to_ansi256(r, g, b))), None)
+            }
+            (Color::Rgb(r, g, b), ColorDepth::Ansi16) => {
+                let (color, intense) = rgb_to_ansi16(r, g, b);
+                (color.into_termcolor(), Some(intense))
+            }
+            (color, _) => (color.into_termcolor(), None),
+        }
+    }
+
     fn from_termcolor(color: termcolor::Color) -> Option<Color> {
         match color {
             termcolor::Color::Black => Some(Color::Black),
@@ -513,11 +942,49 @@ impl FromStr for Color {
     type Err = ParseColorError;
 
     fn from_str(s: &str) -> Result<Color, ParseColorError> {
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(|| ParseColorError::unrecognized(s.into()));
+        }
+
         let tc = termcolor::Color::from_str(s).map_err(ParseColorError::termcolor)?;
         Color::from_termcolor(tc).ok_or_else(|| ParseColorError::unrecognized(s.into()))
     }
 }
 
+/// Parse a CSS-style hex literal (without the leading `#`) into an RGB
+/// color, accepting both the full `rrggbb` form and the `rgb` shorthand,
+/// where each nibble is expanded to a full byte.
+fn parse_hex(hex: &str) -> Option<Color> {
+    fn channel(s: &str) -> Option<u8> {
+        u8::from_str_radix(s, 16).ok()
+    }
+
+    fn expand(c: char) -> Option<u8> {
+        c.to_digit(16).map(|nibble| (nibble * 17) as u8)
+    }
+
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    match hex.len() {
+        6 => {
+            let r = channel(&hex[0..2])?;
+            let g = channel(&hex[2..4])?;
+            let b = channel(&hex[4..6])?;
+            Some(Color::Rgb(r, g, b))
+        }
+        3 => {
+            let mut chars = hex.chars();
+            let r = expand(chars.next()?)?;
+            let g = expand(chars.next()?)?;
+            let b = expand(chars.next()?)?;
+            Some(Color::Rgb(r, g, b))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -581,4 +1048,120 @@ mod tests {
             assert_eq!(input, err.invalid());
         }
     }
+
+    #[test]
+    fn parse_color_hex_valid() {
+        assert_eq!(Color::Rgb(0x33, 0xaa, 0xff), Color::from_str("#33aaff").unwrap());
+        assert_eq!(Color::Rgb(0x33, 0xaa, 0xff), Color::from_str("#3af").unwrap());
+        assert_eq!(Color::Rgb(0, 0, 0), Color::from_str("#000").unwrap());
+    }
+
+    #[test]
+    fn parse_color_hex_invalid() {
+        let inputs = vec![
+            "#",
+            "#12",
+            "#1234",
+            "#gggggg",
+            // "é" is 2 bytes, so this is byte-length 6 but only 5 chars.
+            "#1ébbb",
+        ];
+
+        for input in inputs {
+            let err = Color::from_str(input).unwrap_err();
+            assert_eq!(input, err.invalid());
+        }
+    }
+
+    #[test]
+    fn color_depth_only_degrades_for_auto() {
+        assert_eq!(ColorDepth::TrueColor, ColorDepth::for_write_style(&WriteStyle::Always));
+        assert_eq!(ColorDepth::TrueColor, ColorDepth::for_write_style(&WriteStyle::Never));
+    }
+
+    fn ansi16_style() -> Style {
+        Style {
+            buf: Rc::new(RefCell::new(BufferWriter::stderr(WriteStyle::Always).buffer())),
+            spec: ColorSpec::new(),
+            depth: ColorDepth::Ansi16,
+        }
+    }
+
+    #[test]
+    fn style_set_color_degrades_and_sets_intense() {
+        let mut style = ansi16_style();
+
+        style.set_color(Color::Rgb(255, 0, 0));
+
+        assert_eq!(Some(&termcolor::Color::Red), style.spec.fg());
+        assert!(style.spec.intense());
+    }
+
+    #[test]
+    fn style_set_bg_degrades_and_sets_intense() {
+        let mut style = ansi16_style();
+
+        style.set_bg(Color::Rgb(255, 0, 0));
+
+        assert_eq!(Some(&termcolor::Color::Red), style.spec.bg());
+        assert!(style.spec.intense());
+    }
+
+    #[test]
+    fn rgb_to_ansi256_color_cube() {
+        assert_eq!(196, rgb_to_ansi256(255, 0, 0));
+        assert_eq!(46, rgb_to_ansi256(0, 255, 0));
+        assert_eq!(21, rgb_to_ansi256(0, 0, 255));
+    }
+
+    #[test]
+    fn rgb_to_ansi256_grayscale_ramp() {
+        assert_eq!(232, rgb_to_ansi256(0, 0, 0));
+        assert_eq!(255, rgb_to_ansi256(255, 255, 255));
+    }
+
+    #[test]
+    fn rgb_to_ansi16_picks_closest_and_intensity() {
+        assert_eq!((Color::Red, false), rgb_to_ansi16(128, 0, 0));
+        assert_eq!((Color::Red, true), rgb_to_ansi16(255, 0, 0));
+        assert_eq!((Color::White, true), rgb_to_ansi16(255, 255, 255));
+    }
+
+    #[test]
+    fn style_overrides_parse_fg_bg_and_style() {
+        let overrides = StyleOverrides::parse("level:fg:magenta,level:bg:black,level:style:underline");
+        let level = overrides.level();
+
+        assert_eq!(Some(Color::Magenta), level.fg);
+        assert_eq!(Some(Color::Black), level.bg);
+        assert!(level.underline);
+        assert!(!level.bold);
+    }
+
+    #[test]
+    fn style_overrides_parse_ignores_malformed_directives() {
+        let overrides = StyleOverrides::parse("nonsense,level:fg,level:fg:not_a_color,level:style:glowing");
+        let level = overrides.level();
+
+        assert_eq!(None, level.fg);
+        assert!(!level.bold);
+    }
+
+    #[test]
+    fn style_overrides_parse_ignores_unwired_fields() {
+        // `target`/`args`/`timestamp` are valid grammar but nothing renders
+        // them, so they're reported on stderr (not captured here) and must
+        // not affect the `level` override.
+        let overrides = StyleOverrides::parse("target:fg:cyan,args:style:bold,timestamp:fg:red,level:fg:red");
+
+        assert_eq!(Some(Color::Red), overrides.level().fg);
+        assert!(!overrides.level().bold);
+    }
+
+    #[test]
+    fn style_overrides_default_is_empty() {
+        let overrides = StyleOverrides::default();
+
+        assert_eq!(None, overrides.level().fg);
+    }
 }
\ No newline at end of file